@@ -0,0 +1,6 @@
+// SPDX-FileCopyrightText: Copyright DB InfraGO AG
+// SPDX-License-Identifier: Apache-2.0
+
+fn main() {
+    pyo3_build_config::use_pyo3_cfgs();
+}
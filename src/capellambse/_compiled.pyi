@@ -1,13 +1,19 @@
 # SPDX-FileCopyrightText: Copyright DB InfraGO AG
 # SPDX-License-Identifier: Apache-2.0
 
-from typing import Protocol
+from typing import Protocol, TypedDict
 
 from lxml import etree
 
 class _HasWrite(Protocol):
     def write(self, _: bytes, /) -> None: ...
 
+class _Capabilities(TypedDict):
+    features: list[str]
+    free_threading: bool
+
+def capabilities() -> _Capabilities: ...
+
 def serialize(
     tree: etree._Element,
     /,
@@ -16,4 +22,6 @@ def serialize(
     siblings: bool,
     declare_encoding: bool,
     file: _HasWrite | None,
+    linesep: bytes,
+    sort_namespaces: bool,
 ) -> bytes: ...
@@ -15,6 +15,8 @@ import collections
 import collections.abc as cabc
 import contextlib
 import enum
+import hashlib
+import io
 import itertools
 import logging
 import operator
@@ -132,6 +134,29 @@ def _unquote_ref(ref: str) -> str:
     return ref
 
 
+class _HashingReader:
+    """Hash a file and detect its line separator while it is read."""
+
+    def __init__(self, file: t.BinaryIO) -> None:
+        self.file = file
+        self.hash = hashlib.sha256()
+        self.linesep: bytes | None = None
+        self.__last = b""
+
+    def read(self, size: int = -1) -> bytes:
+        chunk = self.file.read(size)
+        self.hash.update(chunk)
+        if self.linesep is None and chunk:
+            data = self.__last + chunk
+            eol = data.find(b"\n")
+            if eol > 0 and data[eol - 1 : eol] == b"\r":
+                self.linesep = b"\r\n"
+            elif eol >= 0:
+                self.linesep = b"\n"
+            self.__last = chunk[-1:]
+        return chunk
+
+
 def _round_version(v: str, prec: int) -> str:
     """Round a version number.
 
@@ -193,6 +218,11 @@ class ModelFile:
     __idcache: dict[str, etree._Element | None]
     __hrefsources: dict[str, etree._Element]
 
+    loaded_hash: str
+    """SHA-256 hex digest of the file's raw content at load time."""
+    linesep: bytes
+    """The line separator used in the file, reused when writing it."""
+
     @property
     def fragment_type(self) -> FragmentType:
         if self.filename.suffix in SEMANTIC_EXTS:
@@ -215,9 +245,15 @@ class ModelFile:
         )
 
         with handler.open(filename) as f:
+            reader = _HashingReader(f)
             tree = etree.parse(
-                f, etree.XMLParser(remove_blank_text=True, huge_tree=True)
+                reader,
+                etree.XMLParser(remove_blank_text=True, huge_tree=True),
             )
+            while reader.read(io.DEFAULT_BUFFER_SIZE):
+                pass
+        self.loaded_hash = reader.hash.hexdigest()
+        self.linesep = reader.linesep or exs.LINESEP
 
         self.root = tree.getroot()
         self.idcache_rebuild()
@@ -388,7 +424,7 @@ class ModelFile:
         new_root = self.root.makeelement(
             self.root.tag,
             attrib=self.root.attrib,
-            nsmap=dict(sorted(new_nsmap.items())),
+            nsmap=new_nsmap,
         )
         new_root.extend(self.root)
 
@@ -435,8 +471,14 @@ class ModelFile:
         self,
         file: t.BinaryIO,
         encoding: str | _UnspecifiedType = _NOT_SPECIFIED,
+        *,
+        sort_namespaces: bool = True,
     ) -> None:
-        """Write this file's XML into the file specified by ``path``."""
+        """Write this file's XML into the file specified by ``path``.
+
+        See :func:`capellambse.loader.exs.write` for the meaning of
+        *sort_namespaces*.
+        """
         if self.fragment_type == FragmentType.SEMANTIC:
             line_length = exs.LINE_LENGTH
         else:
@@ -462,8 +504,23 @@ class ModelFile:
             **args,  # type: ignore[arg-type]
             line_length=line_length,
             siblings=True,
+            linesep=self.linesep,
+            sort_namespaces=sort_namespaces,
         )
 
+    def content_hash(self) -> str:
+        """Compute a SHA-256 hex digest over the current content.
+
+        The hash is calculated from the XML as it would be written by
+        :meth:`write_xml`. As the serialized XML is not guaranteed to be
+        byte-identical to the file that was loaded, this hash must not
+        be compared with :attr:`loaded_hash`. Compare it with an earlier
+        result of this method instead.
+        """
+        buffer = io.BytesIO()
+        self.write_xml(buffer)
+        return hashlib.sha256(buffer.getvalue()).hexdigest()
+
     def unfollow_href(self, element_id: str) -> etree._Element:
         """Unfollow a fragment link and return the placeholder element.
 
@@ -628,6 +685,9 @@ class MelodyLoader:
             Additional keyword arguments accepted by the file handler in
             use. Please see the respective documentation for more info.
 
+            The ``sort_namespaces`` keyword is handled by the loader
+            itself and passed on to :meth:`ModelFile.write_xml`.
+
         See Also
         --------
         capellambse.filehandler.local.LocalFileHandler.write_transaction :
@@ -647,6 +707,7 @@ class MelodyLoader:
         self.check_duplicate_uuids()
 
         overwrite_corrupt = kw.pop("i_have_a_recent_backup", False)
+        sort_namespaces = kw.pop("sort_namespaces", True)
         if self.__may_be_corrupt and not overwrite_corrupt:
             raise CorruptModelError(
                 "Refusing to save a corrupt model without having a backup"
@@ -670,7 +731,21 @@ class MelodyLoader:
 
                 LOGGER.debug("Saving tree %r to file %s", tree, fname)
                 with self.resources[resname].open(fname, "wb") as f:
-                    tree.write_xml(f)
+                    tree.write_xml(f, sort_namespaces=sort_namespaces)
+
+    def content_hashes(self) -> dict[pathlib.PurePosixPath, str]:
+        """Compute the content hash of each loaded fragment.
+
+        The keys of the returned dict are the same as in :attr:`trees`.
+
+        See Also
+        --------
+        ModelFile.content_hash :
+            Computes the hash for a single fragment.
+        ModelFile.loaded_hash :
+            The hash of the fragment's content at load time.
+        """
+        return {name: tree.content_hash() for name, tree in self.trees.items()}
 
     def update_namespaces(self) -> None:
         """Update the namespace definitions on each fragment root.
@@ -1317,10 +1392,41 @@ class MelodyLoader:
                 return element
         raise KeyError(element_id)
 
-    def find_fragment(self, element: etree._Element) -> pathlib.PurePosixPath:
-        """Find the name of the fragment that contains ``element``."""
+    def find_fragment(
+        self, element: etree._Element | str
+    ) -> pathlib.PurePosixPath:
+        """Find the name of the fragment that contains ``element``.
+
+        The element may also be given by its UUID, in which case it is
+        looked up in all loaded fragments first.
+        """
+        if isinstance(element, str):
+            element = self[element]
         return self._find_fragment(element)[0]
 
+    def locate(
+        self, element: etree._Element | str
+    ) -> tuple[pathlib.PurePosixPath, str]:
+        """Find the fragment and the path of ``element`` inside it.
+
+        The element may also be given by its UUID, like for
+        :meth:`find_fragment`.
+
+        Returns
+        -------
+        tuple[pathlib.PurePosixPath, str]
+            The name of the fragment that contains the element, and an
+            ElementPath expression in Clark notation, which selects the
+            element when passed to ``find()`` on that fragment's root.
+            The path for the root element itself is ``"."``.
+        """
+        if isinstance(element, str):
+            element = self[element]
+        fragment, tree = self._find_fragment(element)
+        if element is tree.root:
+            return (fragment, ".")
+        return (fragment, tree.root.getroottree().getelementpath(element))
+
     def __getitem__(self, key: str) -> etree._Element:
         """Search all loaded fragments for the given UUID."""
         return self.follow_link(None, key)
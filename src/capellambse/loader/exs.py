@@ -23,8 +23,18 @@ import warnings
 
 import lxml.etree
 
+NATIVE_FEATURES = frozenset({"serialize.linesep", "serialize.sort_namespaces"})
+"""Features that the native module must report to be used."""
+
 try:
+    from capellambse._compiled import capabilities as _native_capabilities
     from capellambse._compiled import serialize as _native_serialize
+
+    if _missing := NATIVE_FEATURES - set(_native_capabilities()["features"]):
+        raise ImportError(
+            "Native module is outdated, missing features: "
+            + ", ".join(sorted(_missing))
+        )
 except ImportError:
     if (
         os.environ.get("CIBUILDWHEEL", "0") == "1"
@@ -144,6 +154,8 @@ def write(
     line_length: float = LINE_LENGTH,
     siblings: bool = False,
     declare_encoding: bool = True,
+    linesep: bytes = LINESEP,
+    sort_namespaces: bool = True,
 ) -> None:
     """Write the XML tree to ``file``.
 
@@ -164,6 +176,14 @@ def write(
     declare_encoding
         Whether to include an XML processing instruction declaring the
         encoding at the start of the document.
+    linesep
+        The line separator to use, either ``b"\\n"`` or ``b"\\r\\n"``.
+        Defaults to the platform's native line separator.
+    sort_namespaces
+        Whether to sort namespace declarations. If True, the ``xmi``
+        and ``xsi`` namespaces come first, followed by all others in
+        alphabetical order of their aliases. If False, declarations are
+        written in the order they have in the tree.
     """
     args = {}
     if encoding is not _NOT_SPECIFIED:
@@ -187,6 +207,8 @@ def write(
             siblings=siblings,
             declare_encoding=declare_encoding,
             file=f,
+            linesep=linesep,
+            sort_namespaces=sort_namespaces,
         )
 
 
@@ -201,6 +223,8 @@ def serialize(
     siblings: bool | None = ...,
     declare_encoding: bool = ...,
     file: None = ...,
+    linesep: bytes = ...,
+    sort_namespaces: bool = ...,
 ) -> bytes: ...
 @t.overload
 def serialize(
@@ -213,6 +237,8 @@ def serialize(
     siblings: bool | None = ...,
     declare_encoding: bool = ...,
     file: HasWrite,
+    linesep: bytes = ...,
+    sort_namespaces: bool = ...,
 ) -> None: ...
 def serialize(
     tree: lxml.etree._Element | lxml.etree._ElementTree,
@@ -224,6 +250,8 @@ def serialize(
     siblings: bool | None = None,
     declare_encoding: bool = False,
     file: HasWrite | None = None,
+    linesep: bytes = LINESEP,
+    sort_namespaces: bool = True,
 ) -> bytes | None:
     """Serialize an XML tree.
 
@@ -249,6 +277,14 @@ def serialize(
     file
         A file-like object to write the serialized tree to. If None, the
         serialized tree will be returned as bytes instead.
+    linesep
+        The line separator to use, either ``b"\\n"`` or ``b"\\r\\n"``.
+        Defaults to the platform's native line separator.
+    sort_namespaces
+        Whether to sort namespace declarations. If True, the ``xmi``
+        and ``xsi`` namespaces come first, followed by all others in
+        alphabetical order of their aliases. If False, declarations are
+        written in the order they have in the tree.
 
     Returns
     -------
@@ -282,6 +318,8 @@ def serialize(
         errors = "strict"
     assert isinstance(encoding, str)
     assert isinstance(errors, str)
+    if linesep not in (b"\n", b"\r\n"):
+        raise ValueError(f"Unsupported line separator: {linesep!r}")
 
     if HAS_NATIVE and encoding == "utf-8" and errors == "strict":
         line_length = min(line_length, sys.maxsize)
@@ -291,6 +329,8 @@ def serialize(
             siblings=siblings,
             declare_encoding=declare_encoding,
             file=file,
+            linesep=linesep,
+            sort_namespaces=sort_namespaces,
         )
 
     _python_serialize(
@@ -301,6 +341,8 @@ def serialize(
         siblings=siblings,
         declare_encoding=declare_encoding,
         file=file,
+        linesep=linesep,
+        sort_namespaces=sort_namespaces,
     )
     return None
 
@@ -314,11 +356,13 @@ def _python_serialize(
     siblings: bool,
     declare_encoding: bool,
     file: HasWrite | None,
+    linesep: bytes,
+    sort_namespaces: bool,
 ) -> bytes | None:
     buffer = io.BytesIO()
 
     if declare_encoding:
-        buffer.write(_declare(encoding))
+        buffer.write(_declare(encoding, linesep))
 
     preceding_siblings: cabc.Iterable[lxml.etree._Element]
     following_siblings: cabc.Iterable[lxml.etree._Element]
@@ -334,7 +378,13 @@ def _python_serialize(
     for i in preceding_siblings:
         assert isinstance(i, lxml.etree._Comment), "Non-comment before tree"
         pos = _serialize_comment(
-            buffer, i, encoding=encoding, errors=errors, pos=pos, indent=0
+            buffer,
+            i,
+            encoding=encoding,
+            errors=errors,
+            pos=pos,
+            indent=0,
+            linesep=linesep,
         )
 
     _serialize_element(
@@ -345,6 +395,8 @@ def _python_serialize(
         errors=errors,
         line_length=line_length,
         pos=pos,
+        linesep=linesep,
+        sort_namespaces=sort_namespaces,
     )
     if (root.tail or "").strip():
         pos = _serialize_text(
@@ -355,15 +407,22 @@ def _python_serialize(
             pos=pos,
             multiline=True,
             escape_pattern=P_ESCAPE_TEXT,
+            linesep=linesep,
         )
 
     for i in following_siblings:
         assert isinstance(i, lxml.etree._Comment), "Non-comment after tree"
         pos = _serialize_comment(
-            buffer, i, encoding=encoding, errors=errors, pos=pos, indent=0
+            buffer,
+            i,
+            encoding=encoding,
+            errors=errors,
+            pos=pos,
+            indent=0,
+            linesep=linesep,
         )
 
-    buffer.write(LINESEP)
+    buffer.write(linesep)
 
     if file is not None:
         file.write(buffer.getvalue())
@@ -371,13 +430,13 @@ def _python_serialize(
     return buffer.getvalue()
 
 
-def _declare(encoding: str) -> bytes:
+def _declare(encoding: str, linesep: bytes) -> bytes:
     return b"".join(
         (
             b'<?xml version="1.0" encoding="',
             encoding.upper().encode("ascii"),
             b'"?>',
-            os.linesep.encode("ascii"),
+            linesep,
         )
     )
 
@@ -397,7 +456,10 @@ def _escape_char(
 
 
 def _unmapped_attrs(
-    nsmap: cabc.Mapping[str, str], element: lxml.etree._Element
+    nsmap: cabc.Mapping[str, str],
+    element: lxml.etree._Element,
+    *,
+    sort_namespaces: bool,
 ) -> cabc.Iterator[tuple[str, str]]:
     parent = element.getparent()
     if parent is None:
@@ -420,7 +482,11 @@ def _unmapped_attrs(
             )
 
     assert None not in element.nsmap
-    for nsname, value in sorted(element.nsmap.items(), key=_ns_sortkey):
+    namespaces: cabc.Iterable[tuple[str | None, str]]
+    namespaces = element.nsmap.items()
+    if sort_namespaces:
+        namespaces = sorted(namespaces, key=_ns_sortkey)
+    for nsname, value in namespaces:
         if nsname in parent_ns:
             continue
         yield (f"xmlns:{nsname}", value)
@@ -450,10 +516,11 @@ def _serialize_comment(
     errors: str,
     pos: int,
     indent: int,
+    linesep: bytes,
 ) -> int:
     assert isinstance(comment, lxml.etree._Comment)
 
-    buffer.write(LINESEP)
+    buffer.write(linesep)
     buffer.write(INDENT * indent)
     buffer.write(b"<!--")
     pos = _serialize_text(
@@ -463,6 +530,7 @@ def _serialize_comment(
         errors=errors,
         pos=len(INDENT) * indent,
         escape_pattern=P_ESCAPE_COMMENTS,
+        linesep=linesep,
     )
     buffer.write(b"-->")
 
@@ -474,9 +542,10 @@ def _serialize_comment(
             errors=errors,
             pos=pos,
             escape_pattern=P_ESCAPE_TEXT,
+            linesep=linesep,
         )
     else:
-        buffer.write(LINESEP)
+        buffer.write(linesep)
         buffer.write(INDENT * indent)
         pos = len(INDENT) * indent
     return pos
@@ -491,6 +560,8 @@ def _serialize_element(
     errors: str,
     pos: int = 0,
     line_length: float,
+    linesep: bytes,
+    sort_namespaces: bool,
 ) -> int:
     assert isinstance(element, lxml.etree._Element)
     assert None not in element.nsmap
@@ -503,9 +574,11 @@ def _serialize_element(
     pos += 1 + len(tag)
     attr_indent = INDENT * (indent + 2)
     force_break = False
-    for attr, value in _unmapped_attrs(nsmap, element):
+    for attr, value in _unmapped_attrs(
+        nsmap, element, sort_namespaces=sort_namespaces
+    ):
         if pos > line_length or force_break:
-            buffer.write(LINESEP)
+            buffer.write(linesep)
             buffer.write(attr_indent)
             pos = len(attr_indent)
             force_break = False
@@ -541,6 +614,7 @@ def _serialize_element(
             pos=pos,
             multiline=True,
             escape_pattern=P_ESCAPE_TEXT,
+            linesep=linesep,
         )
         text_content = True
     else:
@@ -548,7 +622,7 @@ def _serialize_element(
 
     for child in element:
         if not text_content:
-            buffer.write(LINESEP)
+            buffer.write(linesep)
             buffer.write(child_indent)
             pos = len(child_indent)
 
@@ -560,6 +634,8 @@ def _serialize_element(
             errors=errors,
             pos=pos,
             line_length=line_length,
+            linesep=linesep,
+            sort_namespaces=sort_namespaces,
         )
         if (element.tail or "").strip():
             pos = _serialize_text(
@@ -569,13 +645,14 @@ def _serialize_element(
                 errors=errors,
                 pos=pos,
                 escape_pattern=P_ESCAPE_TEXT,
+                linesep=linesep,
             )
             text_content = True
         else:
             text_content = False
 
     if len(element) > 0 and not text_content:
-        buffer.write(LINESEP)
+        buffer.write(linesep)
         buffer.write(INDENT * indent)
         pos = len(INDENT) * indent
 
@@ -595,13 +672,14 @@ def _serialize_text(
     pos: int,
     multiline: bool = False,
     escape_pattern: re.Pattern[str],
+    linesep: bytes,
 ) -> int:
     if not text:
         return pos
     i, line = 0, ""
     for i, line in enumerate(text.split("\n")):
         if multiline and i:
-            buffer.write(LINESEP)
+            buffer.write(linesep)
         buffer.write(
             _escape(line, pattern=escape_pattern).encode(encoding, errors)
         )
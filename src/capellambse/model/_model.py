@@ -332,6 +332,10 @@ class MelodyModel:
             Additional keyword arguments accepted by the file handler in
             use. Please see the respective documentation for more info.
 
+            Additionally, ``sort_namespaces=False`` can be passed to
+            keep namespace declarations in their original order,
+            instead of sorting them.
+
         See Also
         --------
         capellambse.filehandler.localfilehandler.LocalFileHandler.write_transaction :
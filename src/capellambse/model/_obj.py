@@ -6,6 +6,7 @@ from __future__ import annotations
 __all__ = [
     "CORE_VIEWPOINT",
     "CachedElementList",
+    "ClassCandidate",
     "ClassName",
     "ElementList",
     "ElementListCouplingMixin",
@@ -154,6 +155,29 @@ class MultipleMatchesError(KeyError):
         return self.args[0]
 
 
+_ClassTuple: te.TypeAlias = """tuple[
+    type[ModelObject],
+    av.AwesomeVersion,
+    av.AwesomeVersion | None,
+]"""
+
+
+@dataclasses.dataclass(frozen=True)
+class ClassCandidate:
+    """A class considered by :meth:`Namespace.explain_class`."""
+
+    cls: type[ModelObject]
+    """The candidate class."""
+    minver: av.AwesomeVersion
+    """The minimum namespace version the class was registered for."""
+    maxver: av.AwesomeVersion | None
+    """The maximum namespace version, or None if open-ended."""
+    selected: bool
+    """Whether :meth:`Namespace.get_class` would return this class."""
+    reason: str
+    """Human readable explanation of the decision."""
+
+
 @dataclasses.dataclass(init=False, frozen=True)
 class Namespace:
     """The interface between the model and a namespace containing classes.
@@ -234,13 +258,10 @@ class Namespace:
         else:
             object.__setattr__(self, "maxver", None)
 
-        ClassTuple: te.TypeAlias = """tuple[
-            type[ModelObject],
-            av.AwesomeVersion,
-            av.AwesomeVersion | None,
-        ]"""
-        self._classes: dict[str, list[ClassTuple]]
+        self._classes: dict[str, list[_ClassTuple]]
         object.__setattr__(self, "_classes", collections.defaultdict(list))
+        self._class_cache: dict[tuple[str, str | None], type[ModelObject]]
+        object.__setattr__(self, "_class_cache", {})
 
     def match_uri(self, uri: str) -> bool | av.AwesomeVersion | None:
         """Match a (potentially versioned) URI against this namespace.
@@ -283,25 +304,97 @@ class Namespace:
     def get_class(
         self, clsname: str, version: str | None = None
     ) -> type[ModelObject]:
+        """Find the class to use for the given name and version.
+
+        Results are cached per class name and requested version. The
+        cache is invalidated whenever a new class is registered.
+
+        See Also
+        --------
+        explain_class :
+            Explains why a certain class was (or was not) selected.
+        """
+        cachekey = (clsname, str(version) if version else None)
+        try:
+            return self._class_cache[cachekey]
+        except KeyError:
+            pass
+
+        classes, _, winner = self.__resolve_class(clsname, version)
+        if winner is None:
+            raise MissingClassError(self, version, clsname)
+        cls = classes[winner][0]
+        self._class_cache[cachekey] = cls
+        return cls
+
+    def explain_class(
+        self, clsname: str, version: str | None = None
+    ) -> list[ClassCandidate]:
+        """Explain how :meth:`get_class` resolves a name and version.
+
+        This is meant for debugging cases where an unexpected class
+        version is picked. It does not use or modify the class cache.
+
+        Returns
+        -------
+        list[ClassCandidate]
+            All classes registered under the given name, in registration
+            order. At most one of them is marked as ``selected``; if
+            none is, :meth:`get_class` would raise a
+            :class:`MissingClassError`. The list is empty if no class
+            with this name is registered at all.
+        """
+        classes, reasons, winner = self.__resolve_class(clsname, version)
+
+        candidates: list[ClassCandidate] = []
+        for i, ((cls, minver, maxver), reason) in enumerate(
+            zip(classes, reasons, strict=True)
+        ):
+            if reason is None and i == winner:
+                reason = "Newest eligible class"
+            elif reason is None:
+                assert winner is not None
+                reason = (
+                    f"Superseded by {classes[winner][0].__qualname__}"
+                    f" with minver {classes[winner][1]}"
+                )
+            candidates.append(
+                ClassCandidate(cls, minver, maxver, i == winner, reason)
+            )
+        return candidates
+
+    def __resolve_class(
+        self, clsname: str, version: str | None
+    ) -> tuple[list[_ClassTuple], list[str | None], int | None]:
+        """Select a class from the registered candidates.
+
+        Returns the candidates, the reason for rejecting each of them
+        (None if it is eligible), and the index of the selected class.
+        Among the eligible classes, the one with the highest minver is
+        selected; on ties, the one registered first wins.
+        """
         if "{VERSION}" in self.uri and not version:
             raise TypeError(
                 f"Versioned namespace, but no version requested: {self.uri}"
             )
 
-        classes = self._classes.get(clsname)
-        if not classes:
-            raise MissingClassError(self, version, clsname)
-
-        eligible: list[tuple[av.AwesomeVersion, type[ModelObject]]] = []
-        for cls, minver, maxver in classes:
-            if version and (version < minver or (maxver and version > maxver)):
-                continue
-            eligible.append((minver, cls))
+        classes = self._classes.get(clsname, [])
+        reasons: list[str | None] = []
+        for _, minver, maxver in classes:
+            if version and version < minver:
+                reasons.append(
+                    f"Requested version {version} is below minver {minver}"
+                )
+            elif version and maxver and version > maxver:
+                reasons.append(
+                    f"Requested version {version} is above maxver {maxver}"
+                )
+            else:
+                reasons.append(None)
 
-        if not eligible:
-            raise MissingClassError(self, version, clsname)
-        eligible.sort(key=lambda i: i[0], reverse=True)
-        return eligible[0][1]
+        eligible = [i for i, r in enumerate(reasons) if r is None]
+        winner = max(eligible, key=lambda i: classes[i][1], default=None)
+        return classes, reasons, winner
 
     def register(
         self,
@@ -324,6 +417,7 @@ class Namespace:
         if maxver is not None:
             maxver = av.AwesomeVersion(maxver)
         classes.append((cls, minver, maxver))
+        self._class_cache.clear()
 
     def trim_version(
         self, version: str | av.AwesomeVersion, /
@@ -1588,6 +1682,46 @@ class ElementList(cabc.MutableSequence[T], t.Generic[T]):
             return ElementList(self._model, newelems, classes.pop())
         return ElementList(self._model, newelems, legacy_by_type=True)
 
+    def iter_chunks(self, size: int, /) -> cabc.Iterator[ElementList[T]]:
+        """Iterate over this list in chunks of at most ``size`` elements.
+
+        Each chunk is a new list, as if obtained by slicing this list.
+        Model objects are only created when they are accessed through a
+        chunk, so that processing a very long list one chunk at a time
+        does not keep all of its objects alive at once.
+        """
+        if size < 1:
+            raise ValueError(f"Chunk size must be positive, not {size}")
+        for start in range(0, len(self), size):
+            yield self[start : start + size]
+
+    def in_model_order(self) -> ElementList[T]:
+        """Return a copy of this list sorted by position in the model.
+
+        Elements are ordered by the fragment that contains them, in the
+        order in which the fragments were loaded, and then by their
+        position in that fragment's XML tree, i.e. parents come before
+        their children and siblings keep their relative order. Elements
+        that are not part of any loaded fragment are placed at the end.
+        """
+        fragments = {
+            tree.root: i
+            for i, tree in enumerate(self._model._loader.trees.values())
+        }
+        indices: dict[etree._Element, dict[etree._Element, int]] = {}
+
+        def sortkey(elem: etree._Element) -> tuple[int, list[int]]:
+            path: list[int] = []
+            while (parent := elem.getparent()) is not None:
+                if parent not in indices:
+                    indices[parent] = {c: i for i, c in enumerate(parent)}
+                path.append(indices[parent][elem])
+                elem = parent
+            path.reverse()
+            return (fragments.get(elem, len(fragments)), path)
+
+        return self._newlist(sorted(self._elements, key=sortkey))
+
     if t.TYPE_CHECKING:
 
         def append(self, value: t.Any) -> None: ...
@@ -2068,7 +2202,15 @@ class ElementListCouplingMixin(ElementList[T], t.Generic[T]):
         assert type(self).__bases__[0] is ElementListCouplingMixin
         return type(self).__bases__[1]
 
-    def create(self, typehint: str | None = None, /, **kw: t.Any) -> T:
+    def create(
+        self,
+        typehint: str | None = None,
+        /,
+        *,
+        before: ModelObject | str | None = None,
+        after: ModelObject | str | None = None,
+        **kw: t.Any,
+    ) -> T:
         """Make a new model object (instance of ModelElement).
 
         Instead of specifying the full ``xsi:type`` including the
@@ -2089,12 +2231,38 @@ class ElementListCouplingMixin(ElementList[T], t.Generic[T]):
             Hints for finding the correct type of element to create. Can
             either be a full or shortened ``xsi:type`` string, or an
             abbreviation defined by the specific Accessor instance.
+        before
+            Insert the new object before this member of the list, given
+            either as model object or by its UUID. By default, the new
+            object is appended at the end.
+        after
+            Insert the new object after this member of the list. Cannot
+            be combined with *before*.
         kw
             Initialize the properties of the new object. Depending on
             the object, some attributes may be required.
         """
+        if before is not None and after is not None:
+            raise TypeError("Cannot specify both 'before' and 'after'")
+        if before is not None:
+            index = self.__index_of_sibling(before)
+        elif after is not None:
+            index = self.__index_of_sibling(after) + 1
+        else:
+            index = len(self)
+
         marker = _descriptors.NewObject(typehint or "", **kw)
-        return self._insert(len(self), marker)
+        return self._insert(index, marker)
+
+    def __index_of_sibling(self, sibling: ModelObject | str) -> int:
+        if isinstance(sibling, str):
+            sibling = self._model.by_uuid(sibling)
+        try:
+            return self._elements.index(sibling._element)
+        except ValueError:
+            raise ValueError(
+                f"Sibling is not a member of this list: {sibling!r}"
+            ) from None
 
     def create_singleattr(self, arg: t.Any) -> T:
         """Make a new model object (instance of ModelElement).
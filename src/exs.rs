@@ -12,20 +12,18 @@ use pyo3::{
     exceptions::{PyTypeError, PyValueError},
     intern,
     prelude::*,
+    sync::PyOnceLock,
     types::{PyDict, PyString, PyType},
 };
 
 const MEM_BUFFER_SIZE: usize = 2 * 1024 * 1024; // 2 MiB
 
-#[cfg(not(windows))]
-const LINESEP: &[u8; 1] = b"\n";
-#[cfg(windows)]
-const LINESEP: &[u8; 2] = b"\r\n";
-
 const INDENT_WIDTH: usize = 2;
-const INDENT_CHAR: u8 = ' ' as u8;
+const INDENT_CHAR: u8 = b' ';
+
+type NamespacedName<'a> = (Option<&'a Cow<'static, str>>, &'static str);
 
-static ALWAYS_EXPANDED_TAGS: LazyLock<HashSet<(Option<&Cow<'static, str>>, &'static str)>> =
+static ALWAYS_EXPANDED_TAGS: LazyLock<HashSet<NamespacedName>> =
     LazyLock::new(|| [(None, "bodies"), (None, "semanticResources")].into());
 static EARLY_NAMESPACES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     [
@@ -35,8 +33,14 @@ static EARLY_NAMESPACES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     .into()
 });
 
+static ETREE_ELEMENT: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static ETREE_COMMENT: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
 #[pyfunction]
-#[pyo3(signature=(tree, /, *, line_length, siblings, declare_encoding, file))]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature=(
+    tree, /, *, line_length, siblings, declare_encoding, file, linesep, sort_namespaces
+))]
 pub fn serialize<'py>(
     py: Python<'py>,
     tree: &'py Bound<PyAny>,
@@ -44,17 +48,22 @@ pub fn serialize<'py>(
     siblings: bool,
     declare_encoding: bool,
     file: Option<Bound<PyAny>>,
+    linesep: Vec<u8>,
+    sort_namespaces: bool,
 ) -> PyResult<Option<Vec<u8>>> {
-    Ok(Serializer::new(py, line_length, file)?
+    Serializer::new(py, line_length, linesep, file)?
+        .sort_namespaces(sort_namespaces)
         .declare_encoding(declare_encoding)?
         .feed_tree(tree, siblings)?
-        .finish()?)
+        .finish()
 }
 
 struct Serializer<'py> {
     buf: Vec<u8>,
     pos: usize,
     line_length: usize,
+    linesep: Vec<u8>,
+    sort_namespaces: bool,
     write: Option<Bound<'py, PyAny>>,
 
     etree_element: Bound<'py, PyType>,
@@ -65,21 +74,18 @@ impl<'py> Serializer<'py> {
     fn new(
         py: Python<'py>,
         line_length: usize,
+        linesep: Vec<u8>,
         output: Option<Bound<'py, PyAny>>,
     ) -> PyResult<Self> {
-        let etree = py.import("lxml.etree").expect("cannot import lxml.etree");
-        let etree_element = etree
-            .getattr("_Element")
-            .expect("lxml.etree does not have _Element")
-            .cast::<PyType>()
-            .expect("lxml.etree._Element is not a type")
-            .clone();
-        let etree_comment = etree
-            .getattr("_Comment")
-            .expect("lxml.etree does not have _Comment")
-            .cast::<PyType>()
-            .expect("lxml.etree._Comment is not a type")
-            .clone();
+        if linesep != b"\n" && linesep != b"\r\n" {
+            Err(PyValueError::new_err(format!(
+                "Unsupported line separator {:?}, expected '\\n' or '\\r\\n'",
+                String::from_utf8_lossy(&linesep)
+            )))?;
+        }
+
+        let etree_element = ETREE_ELEMENT.import(py, "lxml.etree", "_Element")?.clone();
+        let etree_comment = ETREE_COMMENT.import(py, "lxml.etree", "_Comment")?.clone();
 
         let write = match output {
             Some(output) => Some(output.getattr(intern!(py, "write"))?),
@@ -90,6 +96,8 @@ impl<'py> Serializer<'py> {
             buf: Vec::with_capacity(MEM_BUFFER_SIZE),
             pos: 0,
             line_length,
+            linesep,
+            sort_namespaces: true,
             write,
 
             etree_element,
@@ -97,6 +105,11 @@ impl<'py> Serializer<'py> {
         })
     }
 
+    fn sort_namespaces(mut self, sort: bool) -> Self {
+        self.sort_namespaces = sort;
+        self
+    }
+
     fn declare_encoding(mut self, declare: bool) -> PyResult<Self> {
         if declare {
             self.emit_raw_string(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
@@ -233,7 +246,9 @@ impl<'py> Serializer<'py> {
         assert!(e.is_instance(&self.etree_element).unwrap_or(false));
 
         let mut nsmap_alias2uri = extract_nsmap(e);
-        nsmap_alias2uri.sort_unstable_by(namespaces_sort);
+        if self.sort_namespaces {
+            nsmap_alias2uri.sort_unstable_by(namespaces_sort);
+        }
         let nsmap_uri2alias = nsmap_alias2uri
             .iter()
             .map(|(k, v)| (v.to_string_lossy(), k.to_string_lossy()))
@@ -411,12 +426,12 @@ impl<'py> Serializer<'py> {
             );
         };
         let tag = tag.to_cow().expect("namespaced name is not valid UTF-8");
-        assert!(tag.len() > 0, "empty tag");
+        assert!(!tag.is_empty(), "empty tag");
 
         if tag.chars().nth(0) == Some('{') {
             let closing = tag.find("}").expect("malformed tag (no '}')");
             let uri = &tag[1..closing];
-            assert!(uri.len() > 0, "unnamed namespace is not supported");
+            assert!(!uri.is_empty(), "unnamed namespace is not supported");
             let ns = nsmap.get(uri).expect("namespace not in nsmap").clone();
             (Some(ns), tag[closing + 1..].to_string())
         } else {
@@ -455,7 +470,7 @@ impl<'py> Serializer<'py> {
     ) -> PyResult<()> {
         self.digest_namespaced_name(key)?;
         self.emit_raw_string(b"=\"")?;
-        self.digest_string(&value, EscapeCharset::Attribute)?;
+        self.digest_string(value, EscapeCharset::Attribute)?;
         self.emit_raw_string(b"\"")
     }
 }
@@ -463,7 +478,7 @@ impl<'py> Serializer<'py> {
 impl<'py> Serializer<'py> {
     fn emit_linebreak(&mut self, indent: usize) -> PyResult<()> {
         if let Some(ref write) = self.write {
-            let needed_space = LINESEP.len() + INDENT_WIDTH * indent;
+            let needed_space = self.linesep.len() + INDENT_WIDTH * indent;
             assert!(needed_space < MEM_BUFFER_SIZE);
             if self.buf.len() + needed_space > MEM_BUFFER_SIZE {
                 write.call1((&self.buf,))?;
@@ -471,7 +486,7 @@ impl<'py> Serializer<'py> {
             }
         }
 
-        self.buf.extend(LINESEP);
+        self.buf.extend(&self.linesep);
         (0..INDENT_WIDTH * indent).for_each(|_| self.buf.push(INDENT_CHAR));
         self.pos = INDENT_WIDTH * indent;
 
@@ -512,13 +527,13 @@ enum EscapeCharset {
 fn escape<'a>(string: &'a str, charset: EscapeCharset) -> Cow<'a, str> {
     let mut output = None;
     for (i, c) in string.char_indices() {
-        let escape = match (charset, c) {
-            (_, '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F') => true,
-            (EscapeCharset::Attribute, '\x09') => true,
-            (EscapeCharset::Attribute | EscapeCharset::Text, '"' | '&' | '<') => true,
-            (EscapeCharset::Comment, '>') => true,
-            _ => false,
-        };
+        let escape = matches!(
+            (charset, c),
+            (_, '\x00'..='\x08' | '\x0A'..='\x1F' | '\x7F')
+                | (EscapeCharset::Attribute, '\x09')
+                | (EscapeCharset::Attribute | EscapeCharset::Text, '"' | '&' | '<')
+                | (EscapeCharset::Comment, '>')
+        );
 
         if escape {
             if output.is_none() {
@@ -2,11 +2,32 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 mod exs;
 
+/// Functionality that is not present in every build of this module.
+///
+/// Older builds lack some of these, e.g. keyword arguments that were
+/// added to `serialize` later. The Python side checks this list before
+/// using the module, so that a stale build is not used by accident.
+const FEATURES: &[&str] = &["serialize.linesep", "serialize.sort_namespaces"];
+
+/// Report feature information about this build of the module.
+///
+/// This allows feature detection at runtime, without having to probe
+/// for individual functions or arguments.
+#[pyfunction]
+fn capabilities(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let caps = PyDict::new(py);
+    caps.set_item("features", FEATURES.to_vec())?;
+    caps.set_item("free_threading", cfg!(Py_GIL_DISABLED))?;
+    Ok(caps)
+}
+
 #[pymodule(name = "_compiled")]
 fn setup_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(capabilities, m)?)?;
     m.add_function(wrap_pyfunction!(exs::serialize, m)?)?;
 
     Ok(())
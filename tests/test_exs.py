@@ -3,8 +3,11 @@
 from __future__ import annotations
 
 import functools
+import importlib
 import os
 import sys
+import sysconfig
+import types
 
 import pytest
 from lxml import etree
@@ -21,6 +24,8 @@ SERIALIZERS = [
             siblings=True,
             declare_encoding=False,
             file=None,
+            linesep=LF.encode("ascii"),
+            sort_namespaces=True,
         ),
         id="native",
         marks=pytest.mark.skipif(
@@ -36,6 +41,8 @@ SERIALIZERS = [
             siblings=True,
             declare_encoding=False,
             file=None,
+            linesep=LF.encode("ascii"),
+            sort_namespaces=True,
         ),
         id="python",
     ),
@@ -67,3 +74,82 @@ def test_escaping(serializer: functools.partial[bytes], string: str) -> None:
     actual = serializer(tree)
 
     assert actual == expected
+
+
+@pytest.mark.parametrize("serializer", SERIALIZERS)
+@pytest.mark.parametrize("linesep", ["\n", "\r\n"])
+def test_serializers_use_the_requested_line_separator(
+    serializer: functools.partial[bytes], linesep: str
+) -> None:
+    string = linesep.join(["<a>", "  <b/>", "  <c>d", "e</c>", "</a>", ""])
+    parser = etree.XMLParser(remove_blank_text=True)
+    tree = etree.fromstring(string, parser)
+    expected = string.encode("utf-8")
+
+    actual = serializer(tree, linesep=linesep.encode("ascii"))
+
+    assert actual == expected
+
+
+@pytest.mark.parametrize("serializer", SERIALIZERS)
+@pytest.mark.parametrize(
+    ["sort", "expected"],
+    [
+        pytest.param(
+            True,
+            '<a xmlns:xmi="http://www.omg.org/XMI" xmlns:z="urn:z"/>',
+            id="sorted",
+        ),
+        pytest.param(
+            False,
+            '<a xmlns:z="urn:z" xmlns:xmi="http://www.omg.org/XMI"/>',
+            id="original",
+        ),
+    ],
+)
+def test_serializers_optionally_keep_the_namespace_declaration_order(
+    serializer: functools.partial[bytes], sort: bool, expected: str
+) -> None:
+    string = '<a xmlns:z="urn:z" xmlns:xmi="http://www.omg.org/XMI"/>'
+    tree = etree.fromstring(string)
+
+    actual = serializer(tree, sort_namespaces=sort)
+
+    assert actual == (expected + LF).encode("utf-8")
+
+
+@pytest.mark.skipif(not exs.HAS_NATIVE, reason="native module not available")
+def test_native_module_reports_the_features_required_by_exs() -> None:
+    from capellambse import _compiled
+
+    caps = _compiled.capabilities()
+
+    assert exs.NATIVE_FEATURES <= set(caps["features"])
+    expected = bool(sysconfig.get_config_var("Py_GIL_DISABLED"))
+    assert caps["free_threading"] is expected
+
+
+@pytest.mark.parametrize("require_native", [False, True])
+def test_outdated_native_module_is_not_used(
+    monkeypatch: pytest.MonkeyPatch, require_native: bool
+) -> None:
+    outdated = types.ModuleType("capellambse._compiled")
+    outdated.capabilities = lambda: {  # type: ignore[attr-defined]
+        "features": ["serialize.linesep"],
+        "free_threading": False,
+    }
+    outdated.serialize = lambda *_1, **_2: b""  # type: ignore[attr-defined]
+    monkeypatch.setitem(sys.modules, "capellambse._compiled", outdated)
+    monkeypatch.delenv("CIBUILDWHEEL", raising=False)
+    monkeypatch.setenv("CAPELLAMBSE_REQUIRE_NATIVE", str(int(require_native)))
+
+    try:
+        if require_native:
+            with pytest.raises(ImportError, match="serialize.sort_namespaces"):
+                importlib.reload(exs)
+        else:
+            importlib.reload(exs)
+            assert not exs.HAS_NATIVE
+    finally:
+        monkeypatch.undo()
+        importlib.reload(exs)
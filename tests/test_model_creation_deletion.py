@@ -2,6 +2,10 @@
 # SPDX-License-Identifier: Apache-2.0
 """Tests for creating and deleting model elements."""
 
+import pathlib
+import re
+import shutil
+
 import pytest
 
 import capellambse.metamodel as mm
@@ -50,6 +54,31 @@ def test_created_elements_show_up_in_xml_after_adding_them(
     )
 
 
+@pytest.mark.parametrize(
+    ("kwarg", "index"),
+    [
+        pytest.param("before", 0, id="before"),
+        pytest.param("after", 1, id="after"),
+    ],
+)
+@pytest.mark.parametrize("by_uuid", [False, True], ids=["object", "uuid"])
+def test_create_inserts_new_elements_next_to_the_given_sibling(
+    writemodel: m.MelodyModel, kwarg: str, index: int, by_uuid: bool
+) -> None:
+    comps = writemodel.la.root_component.components
+    assert len(comps) == 2, "Precondition not met: Bad list length"
+    sibling = comps[0]
+
+    newobj = comps.create(
+        name="TestComponent",
+        **{kwarg: sibling.uuid if by_uuid else sibling},
+    )
+
+    assert comps.index(newobj) == index
+    reloaded = writemodel.la.root_component.components
+    assert reloaded.index(newobj) == index
+
+
 @pytest.mark.parametrize(
     "deletion_target",
     [0, slice(None, 1)],
@@ -96,6 +125,24 @@ def test_create_adds_missing_namespace_to_fragment(
     assert "Requirements" in writemodel.project._element.nsmap
 
 
+def test_saving_unsorted_keeps_existing_namespaces_in_place(
+    tmp_path: pathlib.Path,
+) -> None:
+    path = tmp_path / "model"
+    shutil.copytree(Models.writemodel, path)
+    semantic = path / "WriteTestModel.capella"
+    declared = re.findall(rb"xmlns:([^=]+)=", semantic.read_bytes())
+    assert b"Requirements" not in declared
+    model = m.MelodyModel(path)
+    module = model.by_uuid("85a31dd7-7755-486b-b803-1df8915e2cf9")
+    module.requirements.create(name="TestReq")
+
+    model.save(sort_namespaces=False)
+
+    saved = re.findall(rb"xmlns:([^=]+)=", semantic.read_bytes())
+    assert saved == [*declared, b"Requirements"]
+
+
 def test_adding_a_namespace_preserves_the_capella_version_comment(
     writemodel: m.MelodyModel,
 ) -> None:
@@ -195,6 +195,30 @@ def test_ElementList_dictlike_setitem(model: m.MelodyModel) -> None:
     assert pv_obj.value == 2
 
 
+def test_ElementList_iter_chunks_splits_the_list_in_order(
+    model: m.MelodyModel,
+) -> None:
+    elements = model.search("LogicalComponent")
+    assert len(elements) > 3
+
+    chunks = list(elements.iter_chunks(3))
+
+    assert all(len(i) == 3 for i in chunks[:-1])
+    assert 0 < len(chunks[-1]) <= 3
+    assert [j for i in chunks for j in i] == list(elements)
+
+
+def test_ElementList_in_model_order_restores_document_order(
+    model: m.MelodyModel,
+) -> None:
+    elements = model.search("LogicalComponent")
+    assert len(elements) > 1
+
+    actual = elements[::-1].in_model_order()
+
+    assert list(actual) == list(elements)
+
+
 def test_MixedElementList_filter_by_type(model: m.MelodyModel) -> None:
     process = model.by_uuid("d588e41f-ec4d-4fa9-ad6d-056868c66274")
     assert isinstance(process, mm.oa.OperationalProcess)
@@ -4,6 +4,8 @@
 from __future__ import annotations
 
 import base64
+import hashlib
+import operator
 import os
 import pathlib
 import re
@@ -274,6 +276,45 @@ def test_MelodyLoader_follow_link_finds_target(link: str) -> None:
         loader.follow_link(None, link)
 
 
+def test_MelodyLoader_find_fragment_accepts_uuids(
+    session_shared_model: capellambse.MelodyModel,
+) -> None:
+    loader = session_shared_model._loader
+    obj = session_shared_model.la.root_component
+    expected = loader.find_fragment(obj._element)
+
+    actual = loader.find_fragment(obj.uuid)
+
+    assert actual == expected
+
+
+@pytest.mark.parametrize("attr", ["project", "la.root_component"])
+def test_MelodyLoader_locate_returns_fragment_and_relative_path(
+    session_shared_model: capellambse.MelodyModel, attr: str
+) -> None:
+    loader = session_shared_model._loader
+    obj = operator.attrgetter(attr)(session_shared_model)
+
+    fragment, path = loader.locate(obj.uuid)
+
+    assert fragment == loader.find_fragment(obj._element)
+    assert loader.trees[fragment].root.find(path) is obj._element
+
+
+def test_MelodyLoader_locate_handles_prefixed_tags_in_diagram_fragments(
+    session_shared_model: capellambse.MelodyModel,
+) -> None:
+    loader = session_shared_model._loader
+    (aird,) = (i for i in loader.trees if i.suffix == ".aird")
+    root = loader.trees[aird].root
+    diagram = next(root.iter("{*}DSemanticDiagram"))
+
+    fragment, path = loader.locate(diagram)
+
+    assert fragment == aird
+    assert root.find(path) is diagram
+
+
 @pytest.mark.parametrize(
     ("path", "subdir", "req_url"),
     [
@@ -636,6 +677,28 @@ def test_model_info_contains_viewpoints_and_capella_version() -> None:
     assert info.capella_version == "7.0.0"
 
 
+def test_loaded_hash_is_calculated_over_the_raw_file_content() -> None:
+    loader = capellambse.loader.MelodyLoader(Models.test7_0)
+
+    for fragment, tree in loader.trees.items():
+        path = Models.test7_0.joinpath(*fragment.parts[1:])
+        expected = hashlib.sha256(path.read_bytes()).hexdigest()
+        assert tree.loaded_hash == expected
+
+
+def test_content_hashes_only_change_for_modified_fragments(
+    model: capellambse.MelodyModel,
+) -> None:
+    loader = model._loader
+    before = loader.content_hashes()
+
+    model.la.root_component.name = "Renamed"
+
+    after = loader.content_hashes()
+    changed = {i for i in before if before[i] != after[i]}
+    assert changed == {loader.find_fragment(model.la.root_component._element)}
+
+
 @pytest.mark.parametrize(
     ("format", "content"),
     [
@@ -706,6 +769,22 @@ def test_updated_namespaces_use_rounded_versions(
     assert nsver == "7.0.0"
 
 
+def test_saving_preserves_the_line_separator_of_each_fragment(
+    tmp_model: pathlib.Path,
+) -> None:
+    (semantic,) = tmp_model.glob("*.capella")
+    semantic.write_bytes(semantic.read_bytes().replace(b"\n", b"\r\n"))
+    (afm,) = tmp_model.glob("*.afm")
+    assert b"\r\n" not in afm.read_bytes()
+    model = capellambse.MelodyModel(tmp_model)
+
+    model.save()
+
+    assert b"\r\n" in semantic.read_bytes()
+    assert b"\n" not in semantic.read_bytes().replace(b"\r\n", b"")
+    assert b"\r\n" not in afm.read_bytes()
+
+
 def test_embed_images_finds_images_in_primary_resource() -> None:
     hdl = memory.MemoryFileHandler()
     hdl.write_file("images/test.png", DUMMY_PNG)
@@ -0,0 +1,85 @@
+# SPDX-FileCopyrightText: Copyright DB InfraGO AG
+# SPDX-License-Identifier: Apache-2.0
+from __future__ import annotations
+
+import pytest
+
+import capellambse.model as m
+
+VERSIONED_URI = "https://example.invalid/test/{VERSION}"
+
+
+def _make_class(
+    ns: m.Namespace, *, minver: str | None = None, maxver: str | None = None
+) -> type[m.ModelElement]:
+    class Thing(m.ModelElement, ns=ns, minver=minver, maxver=maxver):
+        pass
+
+    return Thing
+
+
+def test_get_class_picks_the_newest_eligible_class() -> None:
+    ns = m.Namespace(VERSIONED_URI, "test", maxver="3.0.0")
+    old = _make_class(ns, maxver="1.9.9")
+    new = _make_class(ns, minver="2.0.0")
+
+    assert ns.get_class("Thing", "1.0.0") is old
+    assert ns.get_class("Thing", "2.0.0") is new
+
+
+def test_get_class_cache_is_invalidated_by_registering_a_class() -> None:
+    ns = m.Namespace(VERSIONED_URI, "test", maxver="3.0.0")
+    old = _make_class(ns)
+    assert ns.get_class("Thing", "2.0.0") is old
+
+    new = _make_class(ns, minver="2.0.0")
+
+    assert ns.get_class("Thing", "2.0.0") is new
+    assert ns.get_class("Thing", "1.0.0") is old
+
+
+def test_explain_class_lists_candidates_and_why_they_were_rejected() -> None:
+    ns = m.Namespace(VERSIONED_URI, "test", maxver="3.0.0")
+    old = _make_class(ns, maxver="1.9.9")
+    mid = _make_class(ns, minver="1.0.0")
+    new = _make_class(ns, minver="2.0.0")
+
+    candidates = ns.explain_class("Thing", "1.5.0")
+
+    assert [i.cls for i in candidates] == [old, mid, new]
+    assert [i.selected for i in candidates] == [False, True, False]
+    assert "Superseded" in candidates[0].reason
+    assert "below minver" in candidates[2].reason
+    assert ns.get_class("Thing", "1.5.0") is mid
+
+
+def test_explain_class_selects_nothing_if_get_class_would_fail() -> None:
+    ns = m.Namespace(VERSIONED_URI, "test", maxver="3.0.0")
+    _make_class(ns, maxver="1.9.9")
+
+    candidates = ns.explain_class("Thing", "2.0.0")
+
+    assert len(candidates) == 1
+    assert not candidates[0].selected
+    assert "above maxver" in candidates[0].reason
+    with pytest.raises(m.MissingClassError):
+        ns.get_class("Thing", "2.0.0")
+    assert ns.explain_class("Unknown", "2.0.0") == []
+    with pytest.raises(m.MissingClassError):
+        ns.get_class("Unknown", "2.0.0")
+
+
+@pytest.mark.parametrize(
+    "version", ["0.1.0", "1.0.0", "1.5.0", "1.9.9", "2.0.0", "3.0.0"]
+)
+def test_explain_class_agrees_with_get_class(version: str) -> None:
+    ns = m.Namespace(VERSIONED_URI, "test", maxver="3.0.0")
+    _make_class(ns, maxver="1.9.9")
+    _make_class(ns, minver="1.0.0")
+    _make_class(ns, minver="1.0.0")
+    _make_class(ns, minver="2.0.0")
+
+    candidates = ns.explain_class("Thing", version)
+
+    selected = [i.cls for i in candidates if i.selected]
+    assert selected == [ns.get_class("Thing", version)]